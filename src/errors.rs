@@ -1,5 +1,6 @@
 //! Error types for `SQLite` load extension operations.
 
+use std::os::raw::c_int;
 use thiserror::Error;
 
 /// Errors that can occur when working with `SQLite` load extension functionality.
@@ -7,12 +8,22 @@ use thiserror::Error;
 #[non_exhaustive]
 pub enum LoadExtensionError {
     /// Failed to enable or disable load extension support.
-    #[error("Failed to enable/disable load extension: {0}")]
-    EnableFailed(String),
+    #[error("Failed to enable/disable load extension: {message}")]
+    EnableFailed {
+        /// The `SQLite` error message.
+        message: String,
+        /// The raw result code returned by `sqlite3_enable_load_extension`.
+        code: c_int,
+    },
 
     /// Failed to load an extension from a shared library.
-    #[error("Failed to load extension: {0}")]
-    LoadFailed(String),
+    #[error("Failed to load extension: {message}")]
+    LoadFailed {
+        /// The `SQLite` error message.
+        message: String,
+        /// The raw result code returned by `sqlite3_load_extension`.
+        code: c_int,
+    },
 
     /// The provided extension path contains an interior null byte.
     #[error("Extension path contains an interior null byte")]
@@ -27,13 +38,32 @@ pub enum LoadExtensionError {
     UnsupportedPlatform,
 }
 
+impl LoadExtensionError {
+    /// The raw `SQLite` result code associated with this error, if any.
+    ///
+    /// Returns `Some` for [`EnableFailed`](Self::EnableFailed) and
+    /// [`LoadFailed`](Self::LoadFailed), letting callers distinguish, for
+    /// example, `SQLITE_AUTH` (authorization disabled) from `SQLITE_ERROR`
+    /// (file not found or entry point missing) without string matching.
+    #[must_use]
+    pub fn sqlite_code(&self) -> Option<c_int> {
+        match self {
+            Self::EnableFailed { code, .. } | Self::LoadFailed { code, .. } => Some(*code),
+            Self::InvalidPath | Self::InvalidEntryPoint | Self::UnsupportedPlatform => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_enable_failed_display() {
-        let err = LoadExtensionError::EnableFailed("not authorized".to_string());
+        let err = LoadExtensionError::EnableFailed {
+            message: "not authorized".to_string(),
+            code: 23, // SQLITE_AUTH
+        };
         assert_eq!(
             err.to_string(),
             "Failed to enable/disable load extension: not authorized"
@@ -42,10 +72,32 @@ mod tests {
 
     #[test]
     fn test_load_failed_display() {
-        let err = LoadExtensionError::LoadFailed("file not found".to_string());
+        let err = LoadExtensionError::LoadFailed {
+            message: "file not found".to_string(),
+            code: 1, // SQLITE_ERROR
+        };
         assert_eq!(err.to_string(), "Failed to load extension: file not found");
     }
 
+    #[test]
+    fn test_sqlite_code_accessor() {
+        let err = LoadExtensionError::EnableFailed {
+            message: "not authorized".to_string(),
+            code: 23,
+        };
+        assert_eq!(err.sqlite_code(), Some(23));
+
+        let err = LoadExtensionError::LoadFailed {
+            message: "file not found".to_string(),
+            code: 1,
+        };
+        assert_eq!(err.sqlite_code(), Some(1));
+
+        assert_eq!(LoadExtensionError::InvalidPath.sqlite_code(), None);
+        assert_eq!(LoadExtensionError::InvalidEntryPoint.sqlite_code(), None);
+        assert_eq!(LoadExtensionError::UnsupportedPlatform.sqlite_code(), None);
+    }
+
     #[test]
     fn test_invalid_path_display() {
         let err = LoadExtensionError::InvalidPath;
@@ -0,0 +1,171 @@
+//! Author loadable `SQLite` extensions in Rust.
+//!
+//! This module is the mirror image of [`wasm::register_auto_extension`](crate::wasm):
+//! that module lets WASM builds statically register a precompiled extension's
+//! init function, while this module lets native builds *author* a
+//! `.so`/`.dll`/`.dylib` `SQLite` extension in the first place, by generating
+//! the `extern "C"` entry point `SQLite` calls when it loads the resulting
+//! shared library via
+//! [`SqliteLoadExtensionExt::load_extension`](crate::SqliteLoadExtensionExt).
+//! Pairing the two turns this crate into a round-trip toolkit: author an
+//! extension with [`export_extension!`] in one crate, and load it with
+//! `load_extension` from another.
+//!
+//! This module requires the `loadable-extension` feature and is only
+//! available on native targets.
+
+use libsqlite3_sys::{sqlite3, sqlite3_api_routines};
+use std::os::raw::{c_char, c_int};
+
+/// Result code returned to `SQLite` when extension initialization succeeds.
+pub use libsqlite3_sys::SQLITE_OK;
+
+/// Result code returned to `SQLite` when extension initialization fails.
+pub const SQLITE_ERROR: c_int = 1;
+
+/// The signature every loadable extension's init function must have.
+pub type ExtensionInit = fn(
+    db: *mut sqlite3,
+    pz_err_msg: *mut *mut c_char,
+    p_api: *const sqlite3_api_routines,
+) -> Result<(), Box<dyn std::error::Error>>;
+
+/// Translate the outcome of running a loadable extension's init function
+/// (including a caught panic) into the `SQLITE_OK`/`SQLITE_ERROR` code
+/// `SQLite` expects back from `sqlite3_extension_init`.
+///
+/// This is the logic [`export_extension!`] wires up to the FFI boundary; it
+/// is exposed separately so it can be exercised in tests without generating
+/// `#[no_mangle]` symbols.
+#[must_use]
+pub fn init_result_to_code(
+    result: std::thread::Result<Result<(), Box<dyn std::error::Error>>>,
+) -> c_int {
+    match result {
+        Ok(Ok(())) => SQLITE_OK,
+        Ok(Err(_)) | Err(_) => SQLITE_ERROR,
+    }
+}
+
+/// Generate the `#[no_mangle] extern "C"` entry point `SQLite` calls when it
+/// dynamically loads this crate as an extension.
+///
+/// `$init` must be a function matching [`ExtensionInit`]'s signature. The
+/// generated entry point forwards to it, translating an `Ok(())` return into
+/// `SQLITE_OK` and an `Err` return into `SQLITE_ERROR`, and catching panics
+/// so they cannot unwind across the FFI boundary into `SQLite`'s C code.
+///
+/// By default the entry point is named `sqlite3_extension_init`. `SQLite`
+/// only calls that name automatically if the extension's shared library is
+/// itself named `extension.so`/`libextension.so`/`extension.dll` (it derives
+/// the default entry point name from the library's filename, stripping any
+/// `lib` prefix and non-alphanumeric characters). For any other library
+/// filename, pass `Some("sqlite3_extension_init")` as the `entry_point`
+/// argument to [`load_extension`](crate::SqliteLoadExtensionExt::load_extension)
+/// on the loading side so `SQLite` finds it.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use diesel_load_extension::export_extension;
+///
+/// fn my_init(
+///     _db: *mut diesel_load_extension::extension::ffi::sqlite3,
+///     _pz_err_msg: *mut *mut std::os::raw::c_char,
+///     _p_api: *const diesel_load_extension::extension::ffi::sqlite3_api_routines,
+/// ) -> Result<(), Box<dyn std::error::Error>> {
+///     Ok(())
+/// }
+///
+/// export_extension!(my_init);
+/// ```
+#[macro_export]
+macro_rules! export_extension {
+    ($init:path) => {
+        $crate::export_extension!(sqlite3_extension_init, $init);
+    };
+    ($entry_point:ident, $init:path) => {
+        /// # Safety
+        ///
+        /// Called by `SQLite` with a valid database handle, a valid
+        /// out-pointer for an error message, and a valid `sqlite3_api_routines`
+        /// pointer, per the `sqlite3_load_extension` contract.
+        #[allow(unsafe_code)]
+        #[no_mangle]
+        pub unsafe extern "C" fn $entry_point(
+            db: *mut $crate::extension::ffi::sqlite3,
+            pz_err_msg: *mut *mut std::os::raw::c_char,
+            p_api: *const $crate::extension::ffi::sqlite3_api_routines,
+        ) -> std::os::raw::c_int {
+            let result = std::panic::catch_unwind(|| $init(db, pz_err_msg, p_api));
+            $crate::extension::init_result_to_code(result)
+        }
+    };
+}
+
+/// Re-exported `SQLite` FFI types needed to implement [`ExtensionInit`],
+/// so callers of [`export_extension!`] do not need a direct dependency on
+/// `libsqlite3-sys`.
+pub mod ffi {
+    pub use libsqlite3_sys::{sqlite3, sqlite3_api_routines};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ffi, SQLITE_ERROR, SQLITE_OK};
+    use std::os::raw::c_char;
+
+    fn ok_init(
+        _db: *mut ffi::sqlite3,
+        _pz_err_msg: *mut *mut c_char,
+        _p_api: *const ffi::sqlite3_api_routines,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    fn err_init(
+        _db: *mut ffi::sqlite3,
+        _pz_err_msg: *mut *mut c_char,
+        _p_api: *const ffi::sqlite3_api_routines,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err("extension failed to initialize".into())
+    }
+
+    fn panic_init(
+        _db: *mut ffi::sqlite3,
+        _pz_err_msg: *mut *mut c_char,
+        _p_api: *const ffi::sqlite3_api_routines,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        panic!("intentional panic for test");
+    }
+
+    // Exercised via the two-argument internal arm so each test can generate
+    // its own uniquely-named `#[no_mangle]` entry point without clashing.
+    crate::export_extension!(test_extension_init_ok, ok_init);
+    crate::export_extension!(test_extension_init_err, err_init);
+    crate::export_extension!(test_extension_init_panic, panic_init);
+
+    #[test]
+    fn test_export_extension_ok_returns_sqlite_ok() {
+        let rc = unsafe {
+            test_extension_init_ok(std::ptr::null_mut(), std::ptr::null_mut(), std::ptr::null())
+        };
+        assert_eq!(rc, SQLITE_OK);
+    }
+
+    #[test]
+    fn test_export_extension_err_returns_sqlite_error() {
+        let rc = unsafe {
+            test_extension_init_err(std::ptr::null_mut(), std::ptr::null_mut(), std::ptr::null())
+        };
+        assert_eq!(rc, SQLITE_ERROR);
+    }
+
+    #[test]
+    fn test_export_extension_panic_is_caught_and_returns_sqlite_error() {
+        let rc = unsafe {
+            test_extension_init_panic(std::ptr::null_mut(), std::ptr::null_mut(), std::ptr::null())
+        };
+        assert_eq!(rc, SQLITE_ERROR);
+    }
+}
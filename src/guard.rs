@@ -0,0 +1,124 @@
+//! RAII guard for scoping `SQLite` extension loading.
+
+use crate::{LoadExtensionError, SqliteLoadExtensionExt};
+use diesel::SqliteConnection;
+use std::ops::{Deref, DerefMut};
+
+/// RAII guard that enables `SQLite` extension loading for its lifetime and
+/// disables it again on drop.
+///
+/// [`SqliteLoadExtensionExt::load_extension`] and
+/// [`load_extensions`](SqliteLoadExtensionExt::load_extensions) already manage
+/// the enable/disable window internally. This guard exists for callers who
+/// need the window held open across several calls of their own, for example
+/// loading extensions alongside other `SQLite`-level setup in between. Using
+/// the guard guarantees extension loading is disabled again even if a panic
+/// or an early return (via `?`) happens while it is held.
+///
+/// Calling `load_extension` or `load_extensions` while the guard is held
+/// (including through the guard's `DerefMut`) still disables extension
+/// loading when that call returns; the guard does not enable it again.
+///
+/// # Safety
+///
+/// While a `LoadExtensionGuard` is alive, extension loading is enabled on the
+/// wrapped connection. Untrusted SQL must not run against this connection
+/// while the guard is alive, since a malicious `load_extension()` SQL
+/// function call could load an arbitrary shared library.
+pub struct LoadExtensionGuard<'conn> {
+    conn: &'conn mut SqliteConnection,
+}
+
+impl<'conn> LoadExtensionGuard<'conn> {
+    /// Enable extension loading on `conn` for the lifetime of the returned guard.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadExtensionError::EnableFailed`] if `SQLite` fails to enable
+    /// extension loading. On WASM targets, returns
+    /// [`LoadExtensionError::UnsupportedPlatform`].
+    ///
+    /// # Safety
+    ///
+    /// While the returned guard is alive, extension loading is enabled on
+    /// `conn`. Untrusted SQL must not run against `conn` — including through
+    /// the guard's `DerefMut` — while the guard is alive, since a malicious
+    /// `load_extension()` SQL function call could load an arbitrary shared
+    /// library.
+    pub unsafe fn new(conn: &'conn mut SqliteConnection) -> Result<Self, LoadExtensionError> {
+        // SAFETY: propagated from this function's own safety contract.
+        unsafe { conn.enable_load_extension(true)? };
+        Ok(Self { conn })
+    }
+}
+
+impl Deref for LoadExtensionGuard<'_> {
+    type Target = SqliteConnection;
+
+    fn deref(&self) -> &Self::Target {
+        self.conn
+    }
+}
+
+impl DerefMut for LoadExtensionGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.conn
+    }
+}
+
+impl Drop for LoadExtensionGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: disabling extension loading carries no trust requirement of
+        // its own. Best effort: there is no way to surface an error from
+        // `Drop`, and leaving extension loading enabled is the only failure
+        // mode here.
+        let _ = unsafe { self.conn.enable_load_extension(false) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::prelude::*;
+
+    fn create_connection() -> SqliteConnection {
+        SqliteConnection::establish(":memory:").expect("Failed to create in-memory connection")
+    }
+
+    #[test]
+    fn test_guard_enables_on_construction() {
+        let mut conn = create_connection();
+        let _guard =
+            unsafe { LoadExtensionGuard::new(&mut conn) }.expect("Failed to construct guard");
+    }
+
+    #[test]
+    fn test_guard_disables_on_drop() {
+        let mut conn = create_connection();
+        {
+            let _guard =
+                unsafe { LoadExtensionGuard::new(&mut conn) }.expect("Failed to construct guard");
+        }
+        // Loading is disabled again now, so a raw load attempt (which does not
+        // enable loading itself) is refused as not authorized rather than
+        // failing on the missing file.
+        let c_path = std::ffi::CString::new("/nonexistent/extension.so").unwrap();
+        match crate::native_impl::raw_load_extension(&mut conn, &c_path, None) {
+            Err(LoadExtensionError::LoadFailed { message, .. }) => {
+                assert!(message.contains("not authorized"), "got: {message}");
+            }
+            other => panic!("Expected LoadFailed, got: {other:?}"),
+        }
+        unsafe { conn.enable_load_extension(false) }
+            .expect("Connection should still be usable after guard drop");
+    }
+
+    #[test]
+    fn test_guard_derefs_to_connection() {
+        let mut conn = create_connection();
+        let mut guard =
+            unsafe { LoadExtensionGuard::new(&mut conn) }.expect("Failed to construct guard");
+        let result = unsafe { guard.load_extension("/nonexistent/extension.so", None) };
+        assert!(result.is_err());
+    }
+}
@@ -3,10 +3,16 @@
 
 mod errors;
 mod ffi;
+mod guard;
+
+#[cfg(all(feature = "loadable-extension", not(all(target_family = "wasm", target_os = "unknown"))))]
+pub mod extension;
 
 pub use errors::LoadExtensionError;
+pub use guard::LoadExtensionGuard;
 
 use std::ffi::CString;
+use std::path::Path;
 
 /// Extension trait for [`diesel::SqliteConnection`] providing `SQLite` load extension support.
 #[diagnostic::on_unimplemented(
@@ -31,6 +37,16 @@ pub trait SqliteLoadExtensionExt {
     ///
     /// On WASM targets, returns [`LoadExtensionError::UnsupportedPlatform`] unconditionally.
     ///
+    /// # Safety
+    ///
+    /// While extension loading is enabled, any SQL executed against this
+    /// connection can call `load_extension()` to load an arbitrary shared
+    /// library, running native code with the process's privileges. Callers
+    /// must ensure no untrusted SQL runs against this connection between
+    /// enabling and disabling extension loading. Prefer
+    /// [`load_extension`](Self::load_extension) or [`LoadExtensionGuard`],
+    /// which scope this window for you.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -39,10 +55,12 @@ pub trait SqliteLoadExtensionExt {
     /// use diesel_load_extension::SqliteLoadExtensionExt;
     ///
     /// let mut conn = SqliteConnection::establish(":memory:").unwrap();
-    /// conn.enable_load_extension(true).unwrap();
-    /// conn.enable_load_extension(false).unwrap();
+    /// unsafe {
+    ///     conn.enable_load_extension(true).unwrap();
+    ///     conn.enable_load_extension(false).unwrap();
+    /// }
     /// ```
-    fn enable_load_extension(&mut self, enabled: bool) -> Result<(), LoadExtensionError>;
+    unsafe fn enable_load_extension(&mut self, enabled: bool) -> Result<(), LoadExtensionError>;
 
     /// Load a `SQLite` extension from a shared library file.
     ///
@@ -55,6 +73,8 @@ pub trait SqliteLoadExtensionExt {
     /// # Arguments
     ///
     /// * `path` - Path to the shared library file containing the extension.
+    ///   Accepts anything implementing [`AsRef<Path>`], such as `&str`,
+    ///   `&Path`, or `PathBuf`.
     /// * `entry_point` - Optional name of the extension's entry point function.
     ///   If `None`, `SQLite` uses a default entry point derived from the filename.
     ///
@@ -74,6 +94,13 @@ pub trait SqliteLoadExtensionExt {
     /// panics are unlikely. If a panic did occur (e.g., OOM in an allocation),
     /// a best-effort guard disables extension loading when the stack unwinds.
     ///
+    /// # Safety
+    ///
+    /// `path` must point to a trusted shared library: loading it runs that
+    /// library's native code, including its initializer, with the process's
+    /// privileges. Additionally, no untrusted SQL may run against this
+    /// connection while extension loading is briefly enabled during the call.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -82,12 +109,12 @@ pub trait SqliteLoadExtensionExt {
     /// use diesel_load_extension::{LoadExtensionError, SqliteLoadExtensionExt};
     ///
     /// let mut conn = SqliteConnection::establish(":memory:").unwrap();
-    /// let result = conn.load_extension("nonexistent_extension", None);
-    /// assert!(matches!(result, Err(LoadExtensionError::LoadFailed(_))));
+    /// let result = unsafe { conn.load_extension("nonexistent_extension", None) };
+    /// assert!(matches!(result, Err(LoadExtensionError::LoadFailed { .. })));
     /// ```
-    fn load_extension(
+    unsafe fn load_extension<P: AsRef<Path>>(
         &mut self,
-        path: &str,
+        path: P,
         entry_point: Option<&str>,
     ) -> Result<(), LoadExtensionError>;
 
@@ -118,6 +145,13 @@ pub trait SqliteLoadExtensionExt {
     /// panics are unlikely. If a panic did occur (e.g., OOM in an allocation),
     /// a best-effort guard disables extension loading when the stack unwinds.
     ///
+    /// # Safety
+    ///
+    /// Every path in `extensions` must point to a trusted shared library, per
+    /// the same requirement as [`load_extension`](Self::load_extension). No
+    /// untrusted SQL may run against this connection while extension loading
+    /// is briefly enabled during the call.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -126,26 +160,49 @@ pub trait SqliteLoadExtensionExt {
     /// use diesel_load_extension::{LoadExtensionError, SqliteLoadExtensionExt};
     ///
     /// let mut conn = SqliteConnection::establish(":memory:").unwrap();
-    /// let result = conn.load_extensions(&[
-    ///     ("nonexistent_ext1", None),
-    ///     ("nonexistent_ext2", Some("init")),
-    /// ]);
-    /// assert!(matches!(result, Err(LoadExtensionError::LoadFailed(_))));
+    /// let result = unsafe {
+    ///     conn.load_extensions(&[
+    ///         ("nonexistent_ext1", None),
+    ///         ("nonexistent_ext2", Some("init")),
+    ///     ])
+    /// };
+    /// assert!(matches!(result, Err(LoadExtensionError::LoadFailed { .. })));
     /// ```
-    fn load_extensions(
+    unsafe fn load_extensions<P: AsRef<Path>>(
         &mut self,
-        extensions: &[(&str, Option<&str>)],
+        extensions: &[(P, Option<&str>)],
     ) -> Result<(), LoadExtensionError>;
 }
 
+/// Convert a path to a `CString`, preserving raw OS bytes where possible
+/// instead of forcing a lossy UTF-8 conversion.
+///
+/// # Errors
+///
+/// Returns [`LoadExtensionError::InvalidPath`] if the path contains an
+/// interior null byte, or (on non-Unix platforms) if it is not valid UTF-8.
+fn path_to_cstring(path: &Path) -> Result<CString, LoadExtensionError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        CString::new(path.as_os_str().as_bytes()).map_err(|_| LoadExtensionError::InvalidPath)
+    }
+
+    #[cfg(not(unix))]
+    {
+        let s = path.to_str().ok_or(LoadExtensionError::InvalidPath)?;
+        CString::new(s).map_err(|_| LoadExtensionError::InvalidPath)
+    }
+}
+
 /// Validate and convert extension inputs to C strings.
-fn validate_inputs(
-    extensions: &[(&str, Option<&str>)],
+fn validate_inputs<P: AsRef<Path>>(
+    extensions: &[(P, Option<&str>)],
 ) -> Result<Vec<(CString, Option<CString>)>, LoadExtensionError> {
     extensions
         .iter()
         .map(|(path, entry_point)| {
-            let c_path = CString::new(*path).map_err(|_| LoadExtensionError::InvalidPath)?;
+            let c_path = path_to_cstring(path.as_ref())?;
             let c_entry = entry_point
                 .map(|ep| CString::new(ep).map_err(|_| LoadExtensionError::InvalidEntryPoint))
                 .transpose()?;
@@ -157,9 +214,10 @@ fn validate_inputs(
 // Native implementation — uses real FFI calls.
 #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 mod native_impl {
-    use super::{ffi, validate_inputs, LoadExtensionError, SqliteLoadExtensionExt};
+    use super::{ffi, path_to_cstring, validate_inputs, LoadExtensionError, SqliteLoadExtensionExt};
     use diesel::SqliteConnection;
     use std::ffi::{c_char, CStr, CString};
+    use std::path::Path;
     use std::ptr;
 
     #[allow(unsafe_code)]
@@ -167,7 +225,7 @@ mod native_impl {
         // The `with_raw_connection` API requires a single outer `unsafe` block that
         // encompasses both the method call and the FFI calls within the closure.
         #[allow(clippy::multiple_unsafe_ops_per_block)]
-        fn enable_load_extension(&mut self, enabled: bool) -> Result<(), LoadExtensionError> {
+        unsafe fn enable_load_extension(&mut self, enabled: bool) -> Result<(), LoadExtensionError> {
             let onoff = i32::from(enabled);
             // SAFETY: `with_raw_connection` provides a valid database pointer.
             // `sqlite3_enable_load_extension` receives that valid pointer and a valid
@@ -182,31 +240,35 @@ mod native_impl {
                         let msg = CStr::from_ptr(ffi::sqlite3_errmsg(raw))
                             .to_string_lossy()
                             .into_owned();
-                        return Err(LoadExtensionError::EnableFailed(msg));
+                        return Err(LoadExtensionError::EnableFailed { message: msg, code: rc });
                     }
                     Ok(())
                 })
             }
         }
 
-        fn load_extension(
+        unsafe fn load_extension<P: AsRef<Path>>(
             &mut self,
-            path: &str,
+            path: P,
             entry_point: Option<&str>,
         ) -> Result<(), LoadExtensionError> {
-            let c_path = CString::new(path).map_err(|_| LoadExtensionError::InvalidPath)?;
+            let c_path = path_to_cstring(path.as_ref())?;
             let c_entry = entry_point
                 .map(|ep| CString::new(ep).map_err(|_| LoadExtensionError::InvalidEntryPoint))
                 .transpose()?;
 
-            with_extension_enabled(self, |conn| {
-                raw_load_extension(conn, &c_path, c_entry.as_ref())
-            })
+            // SAFETY: the caller of this `unsafe fn` is responsible for the
+            // trust and concurrency requirements documented on the trait method.
+            unsafe {
+                with_extension_enabled(self, |conn| {
+                    raw_load_extension(conn, &c_path, c_entry.as_ref())
+                })
+            }
         }
 
-        fn load_extensions(
+        unsafe fn load_extensions<P: AsRef<Path>>(
             &mut self,
-            extensions: &[(&str, Option<&str>)],
+            extensions: &[(P, Option<&str>)],
         ) -> Result<(), LoadExtensionError> {
             if extensions.is_empty() {
                 return Ok(());
@@ -214,25 +276,36 @@ mod native_impl {
 
             let c_extensions = validate_inputs(extensions)?;
 
-            with_extension_enabled(self, |conn| {
-                for (c_path, c_entry) in &c_extensions {
-                    raw_load_extension(conn, c_path, c_entry.as_ref())?;
-                }
-                Ok(())
-            })
+            // SAFETY: the caller of this `unsafe fn` is responsible for the
+            // trust and concurrency requirements documented on the trait method.
+            unsafe {
+                with_extension_enabled(self, |conn| {
+                    for (c_path, c_entry) in &c_extensions {
+                        raw_load_extension(conn, c_path, c_entry.as_ref())?;
+                    }
+                    Ok(())
+                })
+            }
         }
     }
 
-    fn with_extension_enabled<T, F>(
+    /// # Safety
+    ///
+    /// `f` must uphold the same trust and concurrency requirements as
+    /// [`SqliteLoadExtensionExt::load_extension`] while extension loading is
+    /// enabled on `conn`.
+    unsafe fn with_extension_enabled<T, F>(
         conn: &mut SqliteConnection,
         f: F,
     ) -> Result<T, LoadExtensionError>
     where
         F: FnOnce(&mut SqliteConnection) -> Result<T, LoadExtensionError>,
     {
-        conn.enable_load_extension(true)?;
+        // SAFETY: propagated from this function's own safety contract.
+        unsafe { conn.enable_load_extension(true)? };
         let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(conn)));
-        let disable_result = conn.enable_load_extension(false);
+        // SAFETY: disabling extension loading carries no trust requirement of its own.
+        let disable_result = unsafe { conn.enable_load_extension(false) };
 
         match result {
             Ok(inner) => {
@@ -250,11 +323,14 @@ mod native_impl {
     #[cfg(test)]
     #[allow(clippy::redundant_pub_crate)]
     pub(super) fn test_with_extension_enabled_panics(conn: &mut SqliteConnection) {
-        let _ = with_extension_enabled(conn, |_conn| -> Result<(), LoadExtensionError> {
-            panic!("intentional panic for test");
-            #[allow(unreachable_code)]
-            Ok(())
-        });
+        // SAFETY: test-only closure that panics before doing anything unsafe.
+        let _ = unsafe {
+            with_extension_enabled(conn, |_conn| -> Result<(), LoadExtensionError> {
+                panic!("intentional panic for test");
+                #[allow(unreachable_code)]
+                Ok(())
+            })
+        };
     }
 
     /// Raw FFI call to `sqlite3_load_extension`, without enable/disable management.
@@ -291,7 +367,7 @@ mod native_impl {
                         ffi::sqlite3_free(err_msg.cast());
                         msg
                     };
-                    return Err(LoadExtensionError::LoadFailed(message));
+                    return Err(LoadExtensionError::LoadFailed { message, code: rc });
                 }
 
                 Ok(())
@@ -303,22 +379,23 @@ mod native_impl {
 // WASM implementation — no unsafe code, returns UnsupportedPlatform.
 #[cfg(all(target_family = "wasm", target_os = "unknown"))]
 mod wasm_impl {
-    use super::{validate_inputs, LoadExtensionError, SqliteLoadExtensionExt};
+    use super::{path_to_cstring, validate_inputs, LoadExtensionError, SqliteLoadExtensionExt};
     use diesel::SqliteConnection;
     use std::ffi::CString;
+    use std::path::Path;
 
     impl SqliteLoadExtensionExt for SqliteConnection {
-        fn enable_load_extension(&mut self, _enabled: bool) -> Result<(), LoadExtensionError> {
+        unsafe fn enable_load_extension(&mut self, _enabled: bool) -> Result<(), LoadExtensionError> {
             Err(LoadExtensionError::UnsupportedPlatform)
         }
 
-        fn load_extension(
+        unsafe fn load_extension<P: AsRef<Path>>(
             &mut self,
-            path: &str,
+            path: P,
             entry_point: Option<&str>,
         ) -> Result<(), LoadExtensionError> {
             // Validate inputs first so callers get specific errors for bad inputs.
-            let _c_path = CString::new(path).map_err(|_| LoadExtensionError::InvalidPath)?;
+            let _c_path = path_to_cstring(path.as_ref())?;
             let _c_entry = entry_point
                 .map(|ep| CString::new(ep).map_err(|_| LoadExtensionError::InvalidEntryPoint))
                 .transpose()?;
@@ -326,9 +403,9 @@ mod wasm_impl {
             Err(LoadExtensionError::UnsupportedPlatform)
         }
 
-        fn load_extensions(
+        unsafe fn load_extensions<P: AsRef<Path>>(
             &mut self,
-            extensions: &[(&str, Option<&str>)],
+            extensions: &[(P, Option<&str>)],
         ) -> Result<(), LoadExtensionError> {
             if extensions.is_empty() {
                 return Ok(());
@@ -390,6 +467,94 @@ pub mod wasm {
     }
 }
 
+/// Deprecated safe wrappers around the now-`unsafe` [`SqliteLoadExtensionExt`] methods.
+///
+/// [`SqliteLoadExtensionExt::enable_load_extension`],
+/// [`load_extension`](SqliteLoadExtensionExt::load_extension), and
+/// [`load_extensions`](SqliteLoadExtensionExt::load_extensions) became `unsafe`
+/// because loading a shared library runs its native code with the process's
+/// privileges. [`SqliteLoadExtensionExtCompat`] re-exposes the same methods
+/// under the same method-call syntax without `unsafe`, so call sites written
+/// against the pre-1.0 safe API keep compiling unmodified as long as this
+/// trait is in scope instead of [`SqliteLoadExtensionExt`]. This module is
+/// gated behind the `deprecated-safe-api` feature; it is a stopgap for
+/// upgrading, not a long-term substitute for auditing call sites and moving
+/// to the `unsafe` methods directly.
+#[cfg(feature = "deprecated-safe-api")]
+pub mod compat {
+    use super::{LoadExtensionError, SqliteLoadExtensionExt};
+    use diesel::SqliteConnection;
+    use std::path::Path;
+
+    /// Deprecated safe counterpart of [`SqliteLoadExtensionExt`], preserving
+    /// the pre-1.0 method-call syntax (`conn.load_extension(...)`) for
+    /// callers who have not yet audited their call sites for trust in the
+    /// loaded path and surrounding SQL.
+    ///
+    /// Import this trait instead of [`SqliteLoadExtensionExt`] to keep old
+    /// call sites compiling unmodified; do not import both in the same scope,
+    /// since their identically-named methods make calls ambiguous.
+    #[diagnostic::on_unimplemented(
+        message = "`SqliteLoadExtensionExtCompat` is only implemented for `diesel::SqliteConnection`"
+    )]
+    pub trait SqliteLoadExtensionExtCompat {
+        /// Deprecated safe wrapper around [`SqliteLoadExtensionExt::enable_load_extension`].
+        #[deprecated(
+            note = "enable_load_extension is now `unsafe`; switch to SqliteLoadExtensionExt and call it directly in an `unsafe` block after verifying no untrusted SQL runs while loading is enabled"
+        )]
+        fn enable_load_extension(&mut self, enabled: bool) -> Result<(), LoadExtensionError>;
+
+        /// Deprecated safe wrapper around [`SqliteLoadExtensionExt::load_extension`].
+        #[deprecated(
+            note = "load_extension is now `unsafe`; switch to SqliteLoadExtensionExt and call it directly in an `unsafe` block after verifying the path is trusted"
+        )]
+        fn load_extension<P: AsRef<Path>>(
+            &mut self,
+            path: P,
+            entry_point: Option<&str>,
+        ) -> Result<(), LoadExtensionError>;
+
+        /// Deprecated safe wrapper around [`SqliteLoadExtensionExt::load_extensions`].
+        #[deprecated(
+            note = "load_extensions is now `unsafe`; switch to SqliteLoadExtensionExt and call it directly in an `unsafe` block after verifying every path is trusted"
+        )]
+        fn load_extensions<P: AsRef<Path>>(
+            &mut self,
+            extensions: &[(P, Option<&str>)],
+        ) -> Result<(), LoadExtensionError>;
+    }
+
+    impl SqliteLoadExtensionExtCompat for SqliteConnection {
+        fn enable_load_extension(&mut self, enabled: bool) -> Result<(), LoadExtensionError> {
+            // SAFETY: this shim exists solely to ease migration off the
+            // pre-1.0 safe API; callers relying on it must still uphold the
+            // safety requirements documented on `enable_load_extension`.
+            unsafe { SqliteLoadExtensionExt::enable_load_extension(self, enabled) }
+        }
+
+        fn load_extension<P: AsRef<Path>>(
+            &mut self,
+            path: P,
+            entry_point: Option<&str>,
+        ) -> Result<(), LoadExtensionError> {
+            // SAFETY: this shim exists solely to ease migration off the
+            // pre-1.0 safe API; callers relying on it must still uphold the
+            // safety requirements documented on `load_extension`.
+            unsafe { SqliteLoadExtensionExt::load_extension(self, path, entry_point) }
+        }
+
+        fn load_extensions<P: AsRef<Path>>(
+            &mut self,
+            extensions: &[(P, Option<&str>)],
+        ) -> Result<(), LoadExtensionError> {
+            // SAFETY: this shim exists solely to ease migration off the
+            // pre-1.0 safe API; callers relying on it must still uphold the
+            // safety requirements documented on `load_extensions`.
+            unsafe { SqliteLoadExtensionExt::load_extensions(self, extensions) }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{native_impl, LoadExtensionError, SqliteLoadExtensionExt};
@@ -403,34 +568,34 @@ mod tests {
     #[test]
     fn test_enable_load_extension() {
         let mut conn = create_connection();
-        conn.enable_load_extension(true)
-            .expect("Failed to enable load extension");
+        unsafe { conn.enable_load_extension(true) }.expect("Failed to enable load extension");
     }
 
     #[test]
     fn test_disable_load_extension() {
         let mut conn = create_connection();
-        conn.enable_load_extension(false)
-            .expect("Failed to disable load extension");
+        unsafe { conn.enable_load_extension(false) }.expect("Failed to disable load extension");
     }
 
     #[test]
     fn test_enable_then_disable_load_extension() {
         let mut conn = create_connection();
-        conn.enable_load_extension(true)
-            .expect("Failed to enable load extension");
-        conn.enable_load_extension(false)
-            .expect("Failed to disable load extension");
+        unsafe {
+            conn.enable_load_extension(true)
+                .expect("Failed to enable load extension");
+            conn.enable_load_extension(false)
+                .expect("Failed to disable load extension");
+        }
     }
 
     #[test]
     fn test_load_nonexistent_extension() {
         let mut conn = create_connection();
 
-        let result = conn.load_extension("/nonexistent/path/to/extension.so", None);
+        let result = unsafe { conn.load_extension("/nonexistent/path/to/extension.so", None) };
         assert!(result.is_err());
         assert!(
-            matches!(result.unwrap_err(), LoadExtensionError::LoadFailed(_)),
+            matches!(result.unwrap_err(), LoadExtensionError::LoadFailed { .. }),
             "Expected LoadFailed error"
         );
     }
@@ -440,15 +605,15 @@ mod tests {
         let mut conn = create_connection();
 
         // load_extension should auto-disable even on failure
-        let _ = conn.load_extension("/nonexistent/extension.so", None);
+        let _ = unsafe { conn.load_extension("/nonexistent/extension.so", None) };
 
         // Verify extension loading is now disabled by using the raw FFI path
         let c_path = CString::new("some_extension").unwrap();
         let result = native_impl::raw_load_extension(&mut conn, &c_path, None);
         assert!(result.is_err());
         match result.unwrap_err() {
-            LoadExtensionError::LoadFailed(msg) => {
-                assert!(!msg.is_empty(), "Expected non-empty error message");
+            LoadExtensionError::LoadFailed { message, .. } => {
+                assert!(!message.is_empty(), "Expected non-empty error message");
             }
             err => panic!("Expected LoadFailed, got: {err:?}"),
         }
@@ -458,11 +623,24 @@ mod tests {
     fn test_load_extension_with_entry_point() {
         let mut conn = create_connection();
 
-        let result = conn.load_extension("/nonexistent/extension.so", Some("my_init"));
+        let result = unsafe { conn.load_extension("/nonexistent/extension.so", Some("my_init")) };
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            LoadExtensionError::LoadFailed(_)
+            LoadExtensionError::LoadFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_load_extension_accepts_path_buf() {
+        let mut conn = create_connection();
+
+        let path = std::path::PathBuf::from("/nonexistent/extension.so");
+        let result = unsafe { conn.load_extension(path, None) };
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            LoadExtensionError::LoadFailed { .. }
         ));
     }
 
@@ -470,7 +648,7 @@ mod tests {
     fn test_invalid_path_null_byte() {
         let mut conn = create_connection();
 
-        let result = conn.load_extension("path\0with_null", None);
+        let result = unsafe { conn.load_extension("path\0with_null", None) };
         assert!(matches!(
             result.unwrap_err(),
             LoadExtensionError::InvalidPath
@@ -481,7 +659,7 @@ mod tests {
     fn test_invalid_entry_point_null_byte() {
         let mut conn = create_connection();
 
-        let result = conn.load_extension("some_extension", Some("entry\0point"));
+        let result = unsafe { conn.load_extension("some_extension", Some("entry\0point")) };
         assert!(matches!(
             result.unwrap_err(),
             LoadExtensionError::InvalidEntryPoint
@@ -491,37 +669,41 @@ mod tests {
     #[test]
     fn test_enable_load_extension_idempotent() {
         let mut conn = create_connection();
-        conn.enable_load_extension(true).unwrap();
-        conn.enable_load_extension(true).unwrap();
-        conn.enable_load_extension(false).unwrap();
-        conn.enable_load_extension(false).unwrap();
+        unsafe {
+            conn.enable_load_extension(true).unwrap();
+            conn.enable_load_extension(true).unwrap();
+            conn.enable_load_extension(false).unwrap();
+            conn.enable_load_extension(false).unwrap();
+        }
     }
 
     #[test]
     fn test_load_extensions_empty_list() {
         let mut conn = create_connection();
-        conn.load_extensions(&[])
-            .expect("Loading empty extension list should succeed");
+        unsafe { conn.load_extensions(&[]) }.expect("Loading empty extension list should succeed");
     }
 
     #[test]
     fn test_load_extensions_nonexistent() {
         let mut conn = create_connection();
-        let result = conn.load_extensions(&[
-            ("/nonexistent/ext1.so", None),
-            ("/nonexistent/ext2.so", None),
-        ]);
+        let result = unsafe {
+            conn.load_extensions(&[
+                ("/nonexistent/ext1.so", None),
+                ("/nonexistent/ext2.so", None),
+            ])
+        };
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            LoadExtensionError::LoadFailed(_)
+            LoadExtensionError::LoadFailed { .. }
         ));
     }
 
     #[test]
     fn test_load_extensions_invalid_path() {
         let mut conn = create_connection();
-        let result = conn.load_extensions(&[("valid_path", None), ("path\0null", None)]);
+        let result =
+            unsafe { conn.load_extensions(&[("valid_path", None), ("path\0null", None)]) };
         assert!(matches!(
             result.unwrap_err(),
             LoadExtensionError::InvalidPath
@@ -532,15 +714,15 @@ mod tests {
     fn test_load_extensions_disables_after_failure() {
         let mut conn = create_connection();
 
-        let _ = conn.load_extensions(&[("/nonexistent/ext.so", None)]);
+        let _ = unsafe { conn.load_extensions(&[("/nonexistent/ext.so", None)]) };
 
         // Verify extension loading is now disabled
         let c_path = CString::new("some_extension").unwrap();
         let result = native_impl::raw_load_extension(&mut conn, &c_path, None);
         assert!(result.is_err());
         match result.unwrap_err() {
-            LoadExtensionError::LoadFailed(msg) => {
-                assert!(!msg.is_empty(), "Expected non-empty error message");
+            LoadExtensionError::LoadFailed { message, .. } => {
+                assert!(!message.is_empty(), "Expected non-empty error message");
             }
             err => panic!("Expected LoadFailed, got: {err:?}"),
         }
@@ -560,10 +742,43 @@ mod tests {
         let result = native_impl::raw_load_extension(&mut conn, &c_path, None);
         assert!(result.is_err());
         match result.unwrap_err() {
-            LoadExtensionError::LoadFailed(msg) => {
-                assert!(!msg.is_empty(), "Expected non-empty error message");
+            LoadExtensionError::LoadFailed { message, .. } => {
+                assert!(!message.is_empty(), "Expected non-empty error message");
             }
             err => panic!("Expected LoadFailed, got: {err:?}"),
         }
     }
 }
+
+#[cfg(all(test, feature = "deprecated-safe-api"))]
+mod compat_tests {
+    use super::compat::SqliteLoadExtensionExtCompat;
+    use super::LoadExtensionError;
+    use diesel::prelude::*;
+    use diesel::SqliteConnection;
+
+    fn create_connection() -> SqliteConnection {
+        SqliteConnection::establish(":memory:").expect("Failed to create in-memory connection")
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_compat_load_extension_matches_unsafe_behavior() {
+        let mut conn = create_connection();
+
+        let result = conn.load_extension("/nonexistent/extension.so", None);
+        assert!(matches!(
+            result.unwrap_err(),
+            LoadExtensionError::LoadFailed { .. }
+        ));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_compat_preserves_method_call_syntax() {
+        let mut conn = create_connection();
+
+        conn.enable_load_extension(true).unwrap();
+        conn.enable_load_extension(false).unwrap();
+    }
+}
@@ -11,11 +11,11 @@ fn test_load_extension_auto_enables_and_disables() {
     let mut conn = create_connection();
 
     // load_extension should work without manually enabling first
-    let result = conn.load_extension("/nonexistent/extension.so", None);
+    let result = unsafe { conn.load_extension("/nonexistent/extension.so", None) };
     assert!(result.is_err());
     match &result.unwrap_err() {
-        LoadExtensionError::LoadFailed(msg) => {
-            assert!(!msg.is_empty(), "Expected non-empty error message");
+        LoadExtensionError::LoadFailed { message, .. } => {
+            assert!(!message.is_empty(), "Expected non-empty error message");
         }
         err => panic!("Expected LoadFailed, got: {err:?}"),
     }
@@ -25,10 +25,12 @@ fn test_load_extension_auto_enables_and_disables() {
 fn test_manual_enable_disable_workflow() {
     let mut conn = create_connection();
 
-    conn.enable_load_extension(true).unwrap();
-    conn.enable_load_extension(false).unwrap();
-    conn.enable_load_extension(true).unwrap();
-    conn.enable_load_extension(false).unwrap();
+    unsafe {
+        conn.enable_load_extension(true).unwrap();
+        conn.enable_load_extension(false).unwrap();
+        conn.enable_load_extension(true).unwrap();
+        conn.enable_load_extension(false).unwrap();
+    }
 }
 
 #[test]
@@ -36,18 +38,20 @@ fn test_multiple_connections_are_independent() {
     let mut conn1 = create_connection();
     let mut conn2 = create_connection();
 
-    // Enable on conn1
-    conn1.enable_load_extension(true).unwrap();
+    unsafe {
+        // Enable on conn1
+        conn1.enable_load_extension(true).unwrap();
 
-    // conn2 should still have loading disabled
-    conn2.enable_load_extension(false).unwrap();
+        // conn2 should still have loading disabled
+        conn2.enable_load_extension(false).unwrap();
+    }
 
     // Load on conn1 works (fails because file doesn't exist, not because unauthorized)
-    let result = conn1.load_extension("/nonexistent/extension.so", None);
+    let result = unsafe { conn1.load_extension("/nonexistent/extension.so", None) };
     assert!(result.is_err());
     match &result.unwrap_err() {
-        LoadExtensionError::LoadFailed(msg) => {
-            assert!(!msg.is_empty(), "Expected non-empty error message");
+        LoadExtensionError::LoadFailed { message, .. } => {
+            assert!(!message.is_empty(), "Expected non-empty error message");
         }
         err => panic!("Expected LoadFailed, got: {err:?}"),
     }
@@ -57,7 +61,7 @@ fn test_multiple_connections_are_independent() {
 fn test_error_messages_are_meaningful() {
     let mut conn = create_connection();
 
-    let result = conn.load_extension("/nonexistent/extension.so", None);
+    let result = unsafe { conn.load_extension("/nonexistent/extension.so", None) };
     let err = result.unwrap_err();
     let msg = err.to_string();
 
@@ -72,7 +76,7 @@ fn test_error_messages_are_meaningful() {
 fn test_empty_path() {
     let mut conn = create_connection();
 
-    let result = conn.load_extension("", None);
+    let result = unsafe { conn.load_extension("", None) };
     assert!(result.is_err());
 }
 
@@ -80,13 +84,13 @@ fn test_empty_path() {
 fn test_invalid_inputs() {
     let mut conn = create_connection();
 
-    let result = conn.load_extension("path\0null", None);
+    let result = unsafe { conn.load_extension("path\0null", None) };
     assert!(matches!(
         result.unwrap_err(),
         LoadExtensionError::InvalidPath
     ));
 
-    let result = conn.load_extension("valid_path", Some("entry\0null"));
+    let result = unsafe { conn.load_extension("valid_path", Some("entry\0null")) };
     assert!(matches!(
         result.unwrap_err(),
         LoadExtensionError::InvalidEntryPoint
@@ -98,14 +102,16 @@ fn test_load_extensions_batch() {
     let mut conn = create_connection();
 
     // All extensions fail because they don't exist, but the first one triggers the error
-    let result = conn.load_extensions(&[
-        ("/nonexistent/ext1.so", None),
-        ("/nonexistent/ext2.so", Some("init")),
-    ]);
+    let result = unsafe {
+        conn.load_extensions(&[
+            ("/nonexistent/ext1.so", None),
+            ("/nonexistent/ext2.so", Some("init")),
+        ])
+    };
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
-        LoadExtensionError::LoadFailed(_)
+        LoadExtensionError::LoadFailed { .. }
     ));
 }
 
@@ -114,16 +120,29 @@ fn test_load_extensions_validates_all_inputs_upfront() {
     let mut conn = create_connection();
 
     // The second extension has an invalid path — should fail before enabling
-    let result = conn.load_extensions(&[("valid_extension", None), ("path\0null", None)]);
+    let result =
+        unsafe { conn.load_extensions(&[("valid_extension", None), ("path\0null", None)]) };
     assert!(matches!(
         result.unwrap_err(),
         LoadExtensionError::InvalidPath
     ));
 }
 
+#[test]
+fn test_load_extension_accepts_path_types() {
+    let mut conn = create_connection();
+
+    // `&Path` and `PathBuf` should work just as well as `&str`.
+    let path = std::path::Path::new("/nonexistent/extension.so");
+    let result = unsafe { conn.load_extension(path, None) };
+    assert!(result.is_err());
+
+    let result = unsafe { conn.load_extension(path.to_path_buf(), None) };
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_load_extensions_empty() {
     let mut conn = create_connection();
-    conn.load_extensions(&[])
-        .expect("Loading empty extension list should succeed");
+    unsafe { conn.load_extensions(&[]) }.expect("Loading empty extension list should succeed");
 }
@@ -32,7 +32,7 @@ fn test_register_auto_extension_idempotent() {
 #[wasm_bindgen_test]
 fn test_enable_load_extension_fails_on_wasm() {
     let mut conn = create_connection();
-    let result = conn.enable_load_extension(true);
+    let result = unsafe { conn.enable_load_extension(true) };
     assert!(result.is_err());
     assert!(
         matches!(result.unwrap_err(), LoadExtensionError::UnsupportedPlatform),
@@ -43,7 +43,7 @@ fn test_enable_load_extension_fails_on_wasm() {
 #[wasm_bindgen_test]
 fn test_disable_load_extension_fails_on_wasm() {
     let mut conn = create_connection();
-    let result = conn.enable_load_extension(false);
+    let result = unsafe { conn.enable_load_extension(false) };
     assert!(result.is_err());
     assert!(
         matches!(result.unwrap_err(), LoadExtensionError::UnsupportedPlatform),
@@ -54,7 +54,7 @@ fn test_disable_load_extension_fails_on_wasm() {
 #[wasm_bindgen_test]
 fn test_load_extension_fails_on_wasm() {
     let mut conn = create_connection();
-    let result = conn.load_extension("some_extension", None);
+    let result = unsafe { conn.load_extension("some_extension", None) };
     assert!(result.is_err());
     assert!(
         matches!(result.unwrap_err(), LoadExtensionError::UnsupportedPlatform),
@@ -65,7 +65,7 @@ fn test_load_extension_fails_on_wasm() {
 #[wasm_bindgen_test]
 fn test_load_extension_with_entry_point_fails_on_wasm() {
     let mut conn = create_connection();
-    let result = conn.load_extension("some_extension", Some("my_init"));
+    let result = unsafe { conn.load_extension("some_extension", Some("my_init")) };
     assert!(result.is_err());
     assert!(
         matches!(result.unwrap_err(), LoadExtensionError::UnsupportedPlatform),
@@ -77,7 +77,7 @@ fn test_load_extension_with_entry_point_fails_on_wasm() {
 fn test_invalid_path_null_byte_on_wasm() {
     let mut conn = create_connection();
     // Null byte validation happens before the UnsupportedPlatform check.
-    let result = conn.load_extension("path\0null", None);
+    let result = unsafe { conn.load_extension("path\0null", None) };
     assert!(matches!(
         result.unwrap_err(),
         LoadExtensionError::InvalidPath
@@ -88,7 +88,7 @@ fn test_invalid_path_null_byte_on_wasm() {
 fn test_invalid_entry_point_null_byte_on_wasm() {
     let mut conn = create_connection();
     // Null byte validation happens before the UnsupportedPlatform check.
-    let result = conn.load_extension("some_extension", Some("entry\0null"));
+    let result = unsafe { conn.load_extension("some_extension", Some("entry\0null")) };
     assert!(matches!(
         result.unwrap_err(),
         LoadExtensionError::InvalidEntryPoint
@@ -98,7 +98,7 @@ fn test_invalid_entry_point_null_byte_on_wasm() {
 #[wasm_bindgen_test]
 fn test_load_extensions_fails_on_wasm() {
     let mut conn = create_connection();
-    let result = conn.load_extensions(&[("some_extension", None)]);
+    let result = unsafe { conn.load_extensions(&[("some_extension", None)]) };
     assert!(result.is_err());
     assert!(
         matches!(result.unwrap_err(), LoadExtensionError::UnsupportedPlatform),
@@ -110,6 +110,6 @@ fn test_load_extensions_fails_on_wasm() {
 fn test_load_extensions_empty_on_wasm() {
     let mut conn = create_connection();
     // Empty list returns Ok(()) without attempting to enable extension loading.
-    conn.load_extensions(&[])
+    unsafe { conn.load_extensions(&[]) }
         .expect("Loading empty extension list should succeed on WASM");
 }